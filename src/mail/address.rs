@@ -1,12 +1,15 @@
 use std::{fmt::Display, str::FromStr};
 
-use super::validation::{validate_part, InvalidPartError};
+use super::{
+    domain::{validate_domain, InvalidDomainError},
+    validation::{validate_part, InvalidPartError},
+};
 
 #[derive(Debug)]
 pub enum ParseAddressError {
     MissingUserOrDomain,
     InvalidUser(InvalidPartError),
-    InvalidDomain(InvalidPartError),
+    InvalidDomain(InvalidDomainError),
 }
 
 /// Represents an email address
@@ -43,10 +46,13 @@ impl Address {
         user: T,
         domain: U,
     ) -> Result<Self, ParseAddressError> {
-        validate_part(&user.into()).map_err(|e| ParseAddressError::InvalidUser(e))?;
-        validate_part(&domain.into()).map_err(|e| ParseAddressError::InvalidDomain(e))?;
+        validate_part(&user.into()).map_err(ParseAddressError::InvalidUser)?;
+        let domain = validate_domain(&domain.into()).map_err(ParseAddressError::InvalidDomain)?;
 
-        Ok(Address::new_unchecked(user, domain))
+        Ok(Self {
+            user: user.into(),
+            domain,
+        })
     }
     /// Creates a new unchecked `Address`.
     ///
@@ -85,6 +91,28 @@ impl FromStr for Address {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e| serde::de::Error::custom(format!("invalid address: {e:?}")))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Address;
@@ -130,4 +158,35 @@ mod test {
         let address = Address::try_new("user", "domain.com").unwrap();
         assert_eq!(address.to_string(), "user@domain.com");
     }
+
+    #[test]
+    fn it_stores_and_displays_internationalized_domains_as_punycode() {
+        let address = Address::try_new("user", "bücher.de").unwrap();
+        assert_eq!(address.to_string(), "user@xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn it_fails_to_create_an_address_with_an_invalid_domain_label() {
+        let address = Address::try_new("user", "-domain.com");
+        assert!(address.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_through_serde_as_its_canonical_string() {
+        let address = Address::try_new("user", "domain.com").unwrap();
+
+        let json = serde_json::to_string(&address).unwrap();
+        assert_eq!(json, "\"user@domain.com\"");
+
+        let deserialized: Address = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.to_string(), address.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_fails_to_deserialize_an_invalid_address() {
+        let result: Result<Address, _> = serde_json::from_str("\"not-an-address\"");
+        assert!(result.is_err());
+    }
 }
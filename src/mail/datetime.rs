@@ -0,0 +1,65 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Formats the current time as an RFC 5322 `date-time`, e.g. `Sun, 26 Jul 2026 09:41:12 +0000`.
+pub fn rfc5322_now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    let days = secs.div_euclid(86400);
+    let secs_of_day = secs.rem_euclid(86400);
+
+    let (year, month, day) = civil_from_days(days);
+    let weekday = WEEKDAYS[weekday_index(days)];
+    let month = MONTHS[(month - 1) as usize];
+
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+    let second = secs_of_day % 60;
+
+    format!("{weekday}, {day:02} {month} {year} {hour:02}:{minute:02}:{second:02} +0000")
+}
+
+fn weekday_index(days_since_epoch: i64) -> usize {
+    // 1970-01-01 (day 0) was a Thursday.
+    (((days_since_epoch.rem_euclid(7)) + 4) % 7) as usize
+}
+
+/// Converts a day count since the Unix epoch into a (year, month, day) civil date, using Howard
+/// Hinnant's `civil_from_days` algorithm (proleptic Gregorian calendar).
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = if month <= 2 { y + 1 } else { y };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::civil_from_days;
+
+    #[test]
+    fn it_converts_the_epoch_to_1970_01_01() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+    }
+
+    #[test]
+    fn it_converts_a_known_day_count_correctly() {
+        // 2026-07-26 is 20,660 days after the Unix epoch.
+        assert_eq!(civil_from_days(20_660), (2026, 7, 26));
+    }
+}
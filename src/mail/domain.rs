@@ -0,0 +1,105 @@
+use super::punycode;
+
+const MAX_LABEL_LEN: usize = 63;
+const MAX_DOMAIN_LEN: usize = 253;
+
+#[derive(Debug)]
+pub enum InvalidDomainError {
+    EmptyLabel,
+    LabelTooLong,
+    DomainTooLong,
+    InvalidCharacter(char),
+}
+
+/// Validates `domain` against the DNS label rules and converts any internationalized labels to
+/// their `xn--` Punycode A-label form, returning the resulting ASCII domain.
+pub fn validate_domain(domain: &str) -> Result<String, InvalidDomainError> {
+    if domain.is_empty() {
+        return Err(InvalidDomainError::EmptyLabel);
+    }
+
+    let encoded = domain
+        .split('.')
+        .map(validate_label)
+        .collect::<Result<Vec<_>, _>>()?
+        .join(".");
+
+    if encoded.len() > MAX_DOMAIN_LEN {
+        return Err(InvalidDomainError::DomainTooLong);
+    }
+
+    Ok(encoded)
+}
+
+/// Validates a single DNS label and returns its ASCII (A-label) form.
+fn validate_label(label: &str) -> Result<String, InvalidDomainError> {
+    if label.is_empty() {
+        return Err(InvalidDomainError::EmptyLabel);
+    }
+
+    if let Some(c) = label
+        .chars()
+        .find(|c| !c.is_alphanumeric() && *c != '-')
+    {
+        return Err(InvalidDomainError::InvalidCharacter(c));
+    }
+
+    if label.starts_with('-') || label.ends_with('-') {
+        return Err(InvalidDomainError::InvalidCharacter('-'));
+    }
+
+    let encoded = if label.is_ascii() {
+        label.to_owned()
+    } else {
+        format!("xn--{}", punycode::encode(label))
+    };
+
+    if encoded.len() > MAX_LABEL_LEN {
+        return Err(InvalidDomainError::LabelTooLong);
+    }
+
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::validate_domain;
+
+    #[test]
+    fn it_accepts_a_plain_ascii_domain() {
+        assert_eq!(validate_domain("domain.com").unwrap(), "domain.com");
+    }
+
+    #[test]
+    fn it_punycode_encodes_an_internationalized_label() {
+        assert_eq!(validate_domain("bücher.de").unwrap(), "xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn it_rejects_an_empty_label() {
+        assert!(validate_domain("domain..com").is_err());
+    }
+
+    #[test]
+    fn it_rejects_a_label_with_a_leading_or_trailing_hyphen() {
+        assert!(validate_domain("-domain.com").is_err());
+        assert!(validate_domain("domain-.com").is_err());
+    }
+
+    #[test]
+    fn it_rejects_a_label_with_an_invalid_character() {
+        assert!(validate_domain("do main.com").is_err());
+    }
+
+    #[test]
+    fn it_rejects_an_over_length_label() {
+        let label = "a".repeat(64);
+        assert!(validate_domain(&format!("{label}.com")).is_err());
+    }
+
+    #[test]
+    fn it_rejects_an_over_length_domain() {
+        let domain = vec!["a".repeat(63); 5].join(".");
+        assert!(validate_domain(&domain).is_err());
+    }
+}
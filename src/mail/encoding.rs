@@ -0,0 +1,160 @@
+const MAX_ENCODED_WORD_LEN: usize = 75;
+/// `=?UTF-8?B?` / `=?UTF-8?Q?` plus the closing `?=`.
+const ENCODED_WORD_OVERHEAD: usize = 12;
+const MAX_PAYLOAD_LEN: usize = MAX_ENCODED_WORD_LEN - ENCODED_WORD_OVERHEAD;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes `name` as one or more RFC 2047 encoded-words if it contains bytes outside printable
+/// ASCII, folding adjacent words with CRLF + space. Printable-ASCII names are returned unchanged.
+pub fn encode_display_name(name: &str) -> String {
+    if name.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+        return name.to_owned();
+    }
+
+    let non_ascii = name.chars().filter(|c| !c.is_ascii()).count();
+    let mostly_ascii = non_ascii * 3 < name.chars().count();
+
+    let chunks: Vec<String> = if mostly_ascii {
+        quoted_printable_chunks(name)
+    } else {
+        base64_chunks(name)
+    };
+
+    let kind = if mostly_ascii { 'Q' } else { 'B' };
+
+    chunks
+        .into_iter()
+        .map(|chunk| format!("=?UTF-8?{kind}?{chunk}?="))
+        .collect::<Vec<_>>()
+        .join("\r\n ")
+}
+
+fn base64_chunks(name: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut buf: Vec<u8> = Vec::new();
+
+    for c in name.chars() {
+        let mut char_bytes = [0u8; 4];
+        let char_bytes = c.encode_utf8(&mut char_bytes).as_bytes();
+
+        if !buf.is_empty() && base64_len(buf.len() + char_bytes.len()) > MAX_PAYLOAD_LEN {
+            chunks.push(base64_encode(&buf));
+            buf.clear();
+        }
+
+        buf.extend_from_slice(char_bytes);
+    }
+
+    if !buf.is_empty() {
+        chunks.push(base64_encode(&buf));
+    }
+
+    chunks
+}
+
+fn base64_len(byte_len: usize) -> usize {
+    byte_len.div_ceil(3) * 4
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(base64_len(bytes.len()));
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+
+        out.push(BASE64_ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(BASE64_ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(n >> 6 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(n & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+
+    out
+}
+
+/// Whether a byte can appear literally in an RFC 2047 `Q`-encoded word.
+fn is_qp_safe(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'!' | b'*' | b'+' | b'-' | b'/')
+}
+
+fn quoted_printable_chunks(name: &str) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for c in name.chars() {
+        let mut char_bytes = [0u8; 4];
+        let char_bytes = c.encode_utf8(&mut char_bytes).as_bytes();
+        let encoded = qp_encode_char(c, char_bytes);
+
+        if current_len > 0 && current_len + encoded.len() > MAX_PAYLOAD_LEN {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        current_len += encoded.len();
+        current.push_str(&encoded);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn qp_encode_char(c: char, bytes: &[u8]) -> String {
+    if c == ' ' {
+        return "_".to_owned();
+    }
+
+    if c.is_ascii() && is_qp_safe(bytes[0]) {
+        return (c as u8 as char).to_string();
+    }
+
+    bytes.iter().map(|b| format!("={:02X}", b)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::encode_display_name;
+
+    #[test]
+    fn it_leaves_ascii_names_unencoded() {
+        assert_eq!(encode_display_name("John Doe"), "John Doe");
+    }
+
+    #[test]
+    fn it_base64_encodes_a_non_ascii_name() {
+        assert_eq!(encode_display_name("日本語"), "=?UTF-8?B?5pel5pys6Kqe?=");
+    }
+
+    #[test]
+    fn it_quoted_printable_encodes_a_mostly_ascii_name() {
+        assert_eq!(encode_display_name("Jurgen Muller e"), "Jurgen Muller e");
+        assert_eq!(encode_display_name("Jürgen"), "=?UTF-8?Q?J=C3=BCrgen?=");
+    }
+
+    #[test]
+    fn it_splits_long_names_into_multiple_encoded_words() {
+        let name = "日".repeat(40);
+        let encoded = encode_display_name(&name);
+
+        assert!(encoded.contains("?=\r\n =?UTF-8?B?"));
+        for word in encoded.split("\r\n ") {
+            assert!(word.len() <= 75);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use super::{header::Header, message::Message, Address};
+
+#[derive(Debug)]
+pub enum EnvelopeError {
+    EmptyForwardPath,
+}
+
+/// Represents the SMTP envelope of a message: the bounce address and recipients actually used for
+/// the transaction, as opposed to the display addresses carried in the message's headers.
+pub struct Envelope {
+    reverse_path: Option<Address>,
+    forward_path: Vec<Address>,
+}
+
+impl Envelope {
+    /// Tries to create an envelope from a reverse path and forward path, returning an error if
+    /// the forward path is empty.
+    ///
+    /// ```
+    /// use brief::mail::{Address, Envelope};
+    ///
+    /// let from: Address = "user@domain.com".parse().unwrap();
+    /// let to: Address = "other@domain.com".parse().unwrap();
+    /// let envelope = Envelope::new(Some(from), vec![to]).unwrap();
+    /// ```
+    pub fn new(
+        reverse_path: Option<Address>,
+        forward_path: Vec<Address>,
+    ) -> Result<Self, EnvelopeError> {
+        if forward_path.is_empty() {
+            return Err(EnvelopeError::EmptyForwardPath);
+        }
+
+        Ok(Self {
+            reverse_path,
+            forward_path,
+        })
+    }
+    /// The bounce/return address, taken from the message's `From` header.
+    pub fn reverse_path(&self) -> Option<&Address> {
+        self.reverse_path.as_ref()
+    }
+    /// The recipients of the SMTP transaction, taken from the message's `To`, `Cc` and `Bcc`
+    /// headers.
+    pub fn forward_path(&self) -> &[Address] {
+        &self.forward_path
+    }
+}
+
+impl TryFrom<&Message> for Envelope {
+    type Error = EnvelopeError;
+
+    fn try_from(message: &Message) -> Result<Self, Self::Error> {
+        let mut reverse_path = None;
+        let mut forward_path = Vec::new();
+
+        for header in message.headers() {
+            match header {
+                Header::From(mailboxes) => {
+                    reverse_path = mailboxes.mailboxes().next().map(|m| m.address().clone());
+                }
+                Header::To(mailboxes) | Header::Cc(mailboxes) | Header::Bcc(mailboxes) => {
+                    forward_path.extend(mailboxes.mailboxes().map(|m| m.address().clone()));
+                }
+                _ => {}
+            }
+        }
+
+        Envelope::new(reverse_path, forward_path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Address, Envelope};
+
+    #[test]
+    fn it_creates_an_envelope_from_valid_data() {
+        let from: Address = "user@domain.com".parse().unwrap();
+        let to: Address = "other@domain.com".parse().unwrap();
+        let envelope = Envelope::new(Some(from), vec![to]);
+
+        assert!(envelope.is_ok());
+    }
+
+    #[test]
+    fn it_fails_to_create_an_envelope_without_recipients() {
+        let from: Address = "user@domain.com".parse().unwrap();
+        let envelope = Envelope::new(Some(from), Vec::new());
+
+        assert!(envelope.is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use super::mailbox::Mailboxes;
+
+/// Represents a single header of a message.
+#[derive(Clone)]
+pub enum Header {
+    From(Mailboxes),
+    To(Mailboxes),
+    Cc(Mailboxes),
+    Bcc(Mailboxes),
+    Subject(String),
+    Date(String),
+    MessageId(String),
+}
+
+impl Header {
+    /// The header's field name, as it appears before the colon.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Header::From(_) => "From",
+            Header::To(_) => "To",
+            Header::Cc(_) => "Cc",
+            Header::Bcc(_) => "Bcc",
+            Header::Subject(_) => "Subject",
+            Header::Date(_) => "Date",
+            Header::MessageId(_) => "Message-ID",
+        }
+    }
+    /// The header's field value, as it appears after the colon. It is not folded to the line
+    /// length, but an RFC 2047-encoded display name in it may already contain `"\r\n "` folds.
+    pub fn value(&self) -> String {
+        match self {
+            Header::From(mailboxes) => mailboxes.to_string(),
+            Header::To(mailboxes) => mailboxes.to_string(),
+            Header::Cc(mailboxes) => mailboxes.to_string(),
+            Header::Bcc(mailboxes) => mailboxes.to_string(),
+            Header::Subject(value) => value.clone(),
+            Header::Date(value) => value.clone(),
+            Header::MessageId(value) => value.clone(),
+        }
+    }
+}
+
+impl Display for Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name(), self.value())
+    }
+}
@@ -2,16 +2,80 @@ use std::{fmt::Display, str::FromStr};
 
 use super::{
     address::ParseAddressError,
-    validation::{validate_part, InvalidPartError},
+    encoding::encode_display_name,
+    validation::{validate_name, InvalidPartError, SPECIALS},
     Address,
 };
 
+/// Whether `name` must be emitted as a quoted-string: it is empty, has leading/trailing
+/// whitespace, or contains an RFC 5322 `specials` character.
+fn needs_quoting(name: &str) -> bool {
+    name.is_empty()
+        || name.starts_with(char::is_whitespace)
+        || name.ends_with(char::is_whitespace)
+        || name.chars().any(|c| c == '.' || SPECIALS.contains(&c))
+}
+
+/// Wraps `name` in a quoted-string, backslash-escaping `"` and `\`.
+fn quote_name(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Formats a display name the way `Mailbox` does: quoted if it's ASCII and needs quoting,
+/// RFC 2047-encoded if it contains bytes outside printable ASCII, or emitted as a bare atom
+/// otherwise.
+fn format_name(name: &str) -> String {
+    if name.is_ascii() && needs_quoting(name) {
+        quote_name(name)
+    } else {
+        encode_display_name(name)
+    }
+}
+
+/// Strips surrounding quotes from a parsed display name and unescapes `\"`/`\\`, if present.
+fn unquote_name(raw: &str) -> String {
+    let trimmed = raw.trim();
+
+    if trimmed.len() < 2 || !trimmed.starts_with('"') || !trimmed.ends_with('"') {
+        return trimmed.to_owned();
+    }
+
+    let mut unescaped = String::with_capacity(trimmed.len());
+    let mut chars = trimmed[1..trimmed.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(escaped) = chars.next() {
+                unescaped.push(escaped);
+                continue;
+            }
+        }
+
+        unescaped.push(c);
+    }
+
+    unescaped
+}
+
 #[derive(Debug)]
 pub enum ParseMailboxError {
     MissingAngleBrackets,
     MissingOpeningAngleBracket,
     MissingClosingAngleBracket,
     WrongOrderAngleBrackets,
+    MissingGroupColon,
+    UnterminatedGroup,
     InvalidName(InvalidPartError),
     InvalidAddress(ParseAddressError),
 }
@@ -44,7 +108,7 @@ impl Mailbox {
         address: Address,
     ) -> Result<Self, ParseMailboxError> {
         if let Some(name) = name {
-            validate_part(&name.into()).map_err(|e| ParseMailboxError::InvalidName(e))?;
+            validate_name(&name.into()).map_err(ParseMailboxError::InvalidName)?;
         }
 
         Ok(Self {
@@ -69,12 +133,16 @@ impl Mailbox {
             address,
         })
     }
+    /// Returns the mailbox's address, discarding its display name.
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
 }
 
 impl Display for Mailbox {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if let Some(name) = &self.name {
-            f.write_str(name)?;
+            f.write_str(&format_name(name))?;
             f.write_str(" ")?;
         }
 
@@ -97,11 +165,11 @@ impl FromStr for Mailbox {
                 let (name_str, rest) = s.split_once('<').unwrap();
                 let address_str = rest.split_once('>').unwrap().0;
 
-                let name = (!name_str.is_empty()).then(|| name_str).or_else(|| None);
+                let name = (!name_str.is_empty()).then_some(name_str);
                 let address: Address = address_str.parse()?;
 
                 Ok(Self {
-                    name: name.map(|v| v.trim().to_owned()),
+                    name: name.map(unquote_name),
                     address,
                 })
             }
@@ -119,16 +187,224 @@ impl FromStr for Mailbox {
     }
 }
 
-/// Represents multiple mailboxes
+/// Represents either a single mailbox or an RFC 5322 group (e.g. `Managers: alice@x.com,
+/// bob@y.com;`) inside an address list.
 #[derive(Clone)]
-pub struct Mailboxes(Vec<Mailbox>);
+pub enum AddressOrGroup {
+    Mailbox(Mailbox),
+    Group { name: String, mailboxes: Vec<Mailbox> },
+}
+
+impl Display for AddressOrGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AddressOrGroup::Mailbox(mailbox) => mailbox.fmt(f),
+            AddressOrGroup::Group { name, mailboxes } => {
+                write!(f, "{}: ", format_name(name))?;
+
+                let mut iter = mailboxes.iter().peekable();
+                while let Some(mailbox) = iter.next() {
+                    mailbox.fmt(f)?;
+
+                    if iter.peek().is_some() {
+                        f.write_str(", ")?;
+                    }
+                }
+
+                f.write_str(";")
+            }
+        }
+    }
+}
+
+impl From<Mailbox> for AddressOrGroup {
+    fn from(value: Mailbox) -> Self {
+        AddressOrGroup::Mailbox(value)
+    }
+}
+
+/// Splits `s` on top-level occurrences of `sep`, treating quoted-strings (honoring `\`-escapes)
+/// as opaque so a `sep` character inside a quoted display name is not mistaken for a separator.
+fn split_unquoted(s: &str, sep: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            current.push(c);
+
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        current.push(escaped);
+                    }
+                }
+                '"' => in_quotes = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        if c == '"' {
+            in_quotes = true;
+            current.push(c);
+        } else if c == sep {
+            parts.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+
+    parts.push(current);
+    parts
+}
+
+/// Finds the index of the first top-level `:` in `s`, skipping one that falls inside a
+/// quoted-string display name.
+fn find_top_level_colon(s: &str) -> Option<usize> {
+    let mut in_quotes = false;
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if in_quotes {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ':' => return Some(i),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits a header value into its top-level comma-separated entries, keeping the comma-separated
+/// members of a `name: ...;` group together with it. Quoted display names are scanned as opaque
+/// so a `:`, `;`, or `,` inside one never starts, ends, or splits a group.
+fn split_address_list(s: &str) -> Result<Vec<String>, ParseMailboxError> {
+    let mut entries = Vec::new();
+    let mut current = String::new();
+    let mut in_group = false;
+    let mut in_quotes = false;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            current.push(c);
+
+            match c {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        current.push(escaped);
+                    }
+                }
+                '"' => in_quotes = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_quotes = true;
+                current.push(c);
+            }
+            ':' if !in_group => {
+                in_group = true;
+                current.push(c);
+            }
+            ';' if in_group => {
+                in_group = false;
+                current.push(c);
+                entries.push(std::mem::take(&mut current));
+            }
+            ',' if !in_group => {
+                entries.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if in_group {
+        return Err(ParseMailboxError::UnterminatedGroup);
+    }
+
+    if !current.trim().is_empty() {
+        entries.push(current);
+    }
+
+    Ok(entries
+        .into_iter()
+        .map(|e| e.trim().to_owned())
+        .filter(|e| !e.is_empty())
+        .collect())
+}
+
+fn parse_entry(entry: &str) -> Result<AddressOrGroup, ParseMailboxError> {
+    if let Some(colon) = find_top_level_colon(entry) {
+        if entry.trim_end().ends_with(';') {
+            let (name, rest) = entry.split_at(colon);
+            let name = unquote_name(name.trim());
+            validate_name(&name).map_err(ParseMailboxError::InvalidName)?;
+            let list = rest[1..].trim().trim_end_matches(';').trim();
+
+            let mailboxes = if list.is_empty() {
+                Vec::new()
+            } else {
+                split_unquoted(list, ',')
+                    .iter()
+                    .map(|m| m.trim().parse::<Mailbox>())
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            return Ok(AddressOrGroup::Group { name, mailboxes });
+        }
+
+        return Err(ParseMailboxError::MissingGroupColon);
+    }
+
+    Ok(AddressOrGroup::Mailbox(entry.parse()?))
+}
+
+/// Represents multiple mailboxes and/or groups
+#[derive(Clone)]
+pub struct Mailboxes(Vec<AddressOrGroup>);
+
+impl Mailboxes {
+    /// Iterates over every individual mailbox, flattening group members in encounter order.
+    pub fn mailboxes(&self) -> impl Iterator<Item = &Mailbox> {
+        self.0.iter().flat_map(|entry| match entry {
+            AddressOrGroup::Mailbox(mailbox) => std::slice::from_ref(mailbox).iter(),
+            AddressOrGroup::Group { mailboxes, .. } => mailboxes.iter(),
+        })
+    }
+    /// The un-flattened entries (plain mailboxes and groups, with their names) in encounter
+    /// order.
+    pub fn entries(&self) -> &[AddressOrGroup] {
+        &self.0
+    }
+}
 
 impl Display for Mailboxes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut iter = self.0.iter().peekable();
 
-        while let Some(mailbox) = iter.next() {
-            mailbox.fmt(f)?;
+        while let Some(entry) = iter.next() {
+            entry.fmt(f)?;
 
             if iter.peek().is_some() {
                 f.write_str(", ")?;
@@ -143,8 +419,9 @@ impl FromStr for Mailboxes {
     type Err = ParseMailboxError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.split(",")
-            .map(|m| m.trim().parse::<Mailbox>())
+        split_address_list(s)?
+            .iter()
+            .map(|entry| parse_entry(entry))
             .collect::<Result<Vec<_>, _>>()
             .map(Mailboxes)
     }
@@ -152,7 +429,51 @@ impl FromStr for Mailboxes {
 
 impl From<Mailbox> for Mailboxes {
     fn from(value: Mailbox) -> Self {
-        Mailboxes(vec![value])
+        Mailboxes(vec![AddressOrGroup::Mailbox(value)])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mailbox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mailbox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e| serde::de::Error::custom(format!("invalid mailbox: {e:?}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mailboxes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mailboxes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e| serde::de::Error::custom(format!("invalid mailboxes: {e:?}")))
     }
 }
 
@@ -160,7 +481,7 @@ impl From<Mailbox> for Mailboxes {
 mod test {
     use crate::mail::Address;
 
-    use super::{Mailbox, Mailboxes};
+    use super::{AddressOrGroup, Mailbox, Mailboxes};
 
     #[test]
     fn it_creates_a_mailbox_from_valid_data() {
@@ -218,9 +539,57 @@ mod test {
         assert_eq!(mailbox.to_string(), "name <user@domain.com>");
     }
 
+    #[test]
+    fn it_formats_a_non_ascii_name_as_an_encoded_word() {
+        let address: Address = "user@domain.com".parse().unwrap();
+        let mailbox = Mailbox::try_new(Some("Jürgen"), address).unwrap();
+
+        assert_eq!(
+            mailbox.to_string(),
+            "=?UTF-8?Q?J=C3=BCrgen?= <user@domain.com>"
+        );
+    }
+
+    #[test]
+    fn it_quotes_a_name_containing_specials() {
+        let address: Address = "user@domain.com".parse().unwrap();
+        let mailbox = Mailbox::try_new(Some("Doe, John"), address).unwrap();
+
+        assert_eq!(mailbox.to_string(), "\"Doe, John\" <user@domain.com>");
+    }
+
+    #[test]
+    fn it_escapes_quotes_and_backslashes_in_a_quoted_name() {
+        let address: Address = "user@domain.com".parse().unwrap();
+        let mailbox = Mailbox::try_new(Some("Jane \"JJ\" \\ Doe,"), address).unwrap();
+
+        assert_eq!(
+            mailbox.to_string(),
+            "\"Jane \\\"JJ\\\" \\\\ Doe,\" <user@domain.com>"
+        );
+    }
+
+    #[test]
+    fn it_creates_a_mailbox_with_an_ordinary_two_word_name() {
+        let address: Address = "user@domain.com".parse().unwrap();
+        let mailbox = Mailbox::try_new(Some("John Doe"), address);
+
+        assert!(mailbox.is_ok());
+        assert_eq!(mailbox.unwrap().to_string(), "John Doe <user@domain.com>");
+    }
+
+    #[test]
+    fn it_round_trips_a_quoted_name_through_from_str() {
+        let mailbox: Mailbox = "\"Doe, John\" <user@domain.com>".parse().unwrap();
+
+        assert_eq!(mailbox.to_string(), "\"Doe, John\" <user@domain.com>");
+    }
+
     #[test]
     fn it_formats_mailboxes_correctly_single() {
-        let mailboxes = Mailboxes(vec!["name <user@domain.com>".parse().unwrap()]);
+        let mailboxes = Mailboxes(vec![AddressOrGroup::Mailbox(
+            "name <user@domain.com>".parse().unwrap(),
+        )]);
 
         assert_eq!(mailboxes.to_string(), "name <user@domain.com>");
     }
@@ -228,8 +597,8 @@ mod test {
     #[test]
     fn it_formats_mailboxes_correctly_multiple() {
         let mailboxes = Mailboxes(vec![
-            "name <user@domain.com>".parse().unwrap(),
-            "nametwo <usertwo@domaintwo.com>".parse().unwrap(),
+            AddressOrGroup::Mailbox("name <user@domain.com>".parse().unwrap()),
+            AddressOrGroup::Mailbox("nametwo <usertwo@domaintwo.com>".parse().unwrap()),
         ]);
 
         assert_eq!(
@@ -240,10 +609,10 @@ mod test {
 
     #[test]
     fn it_parses_mailboxes_correctly_single() {
-        let mailbox = "name <user@domain.com>".parse::<Mailboxes>();
+        let mailboxes = "name <user@domain.com>".parse::<Mailboxes>();
 
-        assert!(mailbox.is_ok());
-        assert_eq!(mailbox.unwrap().0.len(), 1);
+        assert!(mailboxes.is_ok());
+        assert_eq!(mailboxes.unwrap().0.len(), 1);
     }
 
     #[test]
@@ -260,4 +629,137 @@ mod test {
             assert!(v.parse::<Mailboxes>().is_ok())
         }
     }
+
+    #[test]
+    fn it_parses_a_group_into_a_single_entry() {
+        let mailboxes = "Managers: alice@x.com, bob@y.com;"
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        assert_eq!(mailboxes.0.len(), 1);
+        assert_eq!(mailboxes.mailboxes().count(), 2);
+    }
+
+    #[test]
+    fn it_parses_a_group_alongside_plain_mailboxes() {
+        let mailboxes = "alice <alice@x.com>, Managers: bob@y.com, carol@z.com;, dave@w.com"
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        assert_eq!(mailboxes.0.len(), 3);
+        assert_eq!(mailboxes.mailboxes().count(), 4);
+    }
+
+    #[test]
+    fn it_round_trips_a_group_through_display() {
+        let mailboxes = "Managers: alice@x.com, bob@y.com;"
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        assert_eq!(
+            mailboxes.to_string(),
+            "Managers: <alice@x.com>, <bob@y.com>;"
+        );
+    }
+
+    #[test]
+    fn it_quotes_a_group_name_containing_specials() {
+        let mailboxes = r#""Weird, Name": alice@x.com;"#.parse::<Mailboxes>().unwrap();
+
+        assert_eq!(mailboxes.to_string(), "\"Weird, Name\": <alice@x.com>;");
+    }
+
+    #[test]
+    fn it_escapes_quotes_and_backslashes_in_a_group_name() {
+        let mailboxes = r#""Weird \"Name\" \\": alice@x.com;"#
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        assert_eq!(
+            mailboxes.to_string(),
+            "\"Weird \\\"Name\\\" \\\\\": <alice@x.com>;"
+        );
+    }
+
+    #[test]
+    fn it_rejects_a_group_name_containing_a_control_character() {
+        let mailboxes = "Mali\r\ncious: alice@x.com;".parse::<Mailboxes>();
+        assert!(mailboxes.is_err());
+    }
+
+    #[test]
+    fn it_exposes_un_flattened_entries_including_group_names() {
+        let mailboxes = "alice <alice@x.com>, Managers: bob@y.com, carol@z.com;"
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        let entries = mailboxes.entries();
+        assert_eq!(entries.len(), 2);
+
+        assert!(matches!(entries[0], AddressOrGroup::Mailbox(_)));
+        match &entries[1] {
+            AddressOrGroup::Group { name, mailboxes } => {
+                assert_eq!(name, "Managers");
+                assert_eq!(mailboxes.len(), 2);
+            }
+            AddressOrGroup::Mailbox(_) => panic!("expected a group"),
+        }
+    }
+
+    #[test]
+    fn it_fails_on_an_unterminated_group() {
+        let mailboxes = "Managers: alice@x.com, bob@y.com".parse::<Mailboxes>();
+        assert!(mailboxes.is_err());
+    }
+
+    #[test]
+    fn it_parses_a_quoted_name_containing_a_colon_as_a_plain_mailbox() {
+        let mailboxes = "\"Smith: Jr\" <bob@x.com>".parse::<Mailboxes>().unwrap();
+
+        assert_eq!(mailboxes.0.len(), 1);
+        assert_eq!(
+            mailboxes.mailboxes().next().unwrap().to_string(),
+            "\"Smith: Jr\" <bob@x.com>"
+        );
+    }
+
+    #[test]
+    fn it_parses_a_group_whose_member_has_a_quoted_name_containing_a_comma() {
+        let mailboxes = "Managers: \"Doe, John\" <doe@x.com>, bob@y.com;"
+            .parse::<Mailboxes>()
+            .unwrap();
+
+        assert_eq!(mailboxes.0.len(), 1);
+        assert_eq!(mailboxes.mailboxes().count(), 2);
+        assert_eq!(
+            mailboxes.mailboxes().next().unwrap().to_string(),
+            "\"Doe, John\" <doe@x.com>"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_a_mailbox_through_serde() {
+        let mailbox: Mailbox = "name <user@domain.com>".parse().unwrap();
+
+        let json = serde_json::to_string(&mailbox).unwrap();
+        assert_eq!(json, "\"name <user@domain.com>\"");
+
+        let deserialized: Mailbox = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.to_string(), mailbox.to_string());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_mailboxes_through_serde() {
+        let mailboxes: Mailboxes =
+            "name <user@domain.com>, nametwo <usertwo@domaintwo.com>"
+                .parse()
+                .unwrap();
+
+        let json = serde_json::to_string(&mailboxes).unwrap();
+        let deserialized: Mailboxes = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized.to_string(), mailboxes.to_string());
+    }
 }
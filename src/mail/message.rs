@@ -1,13 +1,99 @@
-use super::{header::Header, mailbox::Mailboxes};
+use std::{
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{datetime::rfc5322_now, header::Header, mailbox::Mailboxes};
+
+/// The maximum length of a folded header line, per RFC 5322.
+const MAX_LINE_LEN: usize = 78;
 
 pub struct Message {
     headers: Vec<Header>,
-    // body
+    body: String,
+}
+
+impl Message {
+    /// The headers that make up the message.
+    pub(crate) fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for header in &self.headers {
+            // Bcc recipients must reach the SMTP envelope (see `Envelope::try_from`) without
+            // appearing in the headers every recipient receives.
+            if matches!(header, Header::Bcc(_)) {
+                continue;
+            }
+
+            write!(f, "{}\r\n", fold_header(header.name(), &header.value()))?;
+        }
+
+        write!(f, "\r\n{}", self.body)
+    }
+}
+
+/// Folds a header's rendered line at whitespace so that no physical line exceeds
+/// [`MAX_LINE_LEN`] octets, indenting continuation lines with a single space. Words are never
+/// broken, so a word too long to fit on a line of its own leaves that line longer. A value that
+/// already contains `"\r\n "` (e.g. RFC 2047 encoded-words folded by `encode_display_name`) is
+/// treated as pre-folded at those points rather than re-wrapped, so they aren't re-merged into a
+/// line that then gets folded again on top of them.
+fn fold_header(name: &str, value: &str) -> String {
+    let prefix = format!("{name}: ");
+    let mut lines = Vec::new();
+    let mut current = prefix.clone();
+
+    for (segment_i, segment) in value.split("\r\n ").enumerate() {
+        if segment_i > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        for word in segment.split(' ') {
+            let addition = if current == prefix {
+                word.to_owned()
+            } else {
+                format!(" {word}")
+            };
+
+            if !current.is_empty()
+                && current != prefix
+                && current.len() + addition.len() > MAX_LINE_LEN
+            {
+                lines.push(current);
+                current = format!(" {word}");
+            } else {
+                current.push_str(&addition);
+            }
+        }
+    }
+
+    lines.push(current);
+    lines.join("\r\n")
+}
+
+/// Generates a best-effort unique `Message-ID` value from the current time and process id.
+fn generate_message_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    format!("<{}.{}@localhost>", nanos, std::process::id())
 }
 
 pub struct MessageBuilder {
     headers: Vec<Header>,
-    // body
+    body: String,
+}
+
+impl Default for MessageBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MessageBuilder {
@@ -21,6 +107,7 @@ impl MessageBuilder {
     pub fn new() -> Self {
         Self {
             headers: Vec::new(),
+            body: String::new(),
         }
     }
     /// Adds a header to the message.
@@ -32,8 +119,10 @@ impl MessageBuilder {
     /// let from_header = Header::From(sender.into());
     /// let builder = MessageBuilder::new().header(from_header);
     /// ```
-    pub fn header(mut self, header: Header) {
+    pub fn header(mut self, header: Header) -> Self {
         self.headers.push(header);
+
+        self
     }
     /// Convenience method for adding a 'From' header.
     ///
@@ -61,7 +150,20 @@ impl MessageBuilder {
 
         self
     }
-    /// Builds a message using the given headers and body.
+    /// Sets the message's body.
+    ///
+    /// ```
+    /// use brief::mail::MessageBuilder;
+    ///
+    /// let builder = MessageBuilder::new().body("Hello, world!");
+    /// ```
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+    /// Builds a message using the given headers and body, adding `Date` and `Message-ID`
+    /// headers if they weren't set explicitly.
     ///
     /// ```
     /// use brief::mail::MessageBuilder;
@@ -69,19 +171,138 @@ impl MessageBuilder {
     /// let builder = MessageBuilder::new().build();
     /// ```
     pub fn build(self) -> Message {
+        let mut headers = self.headers;
+
+        if !headers.iter().any(|h| matches!(h, Header::Date(_))) {
+            headers.push(Header::Date(rfc5322_now()));
+        }
+
+        if !headers.iter().any(|h| matches!(h, Header::MessageId(_))) {
+            headers.push(Header::MessageId(generate_message_id()));
+        }
+
         Message {
-            headers: Vec::new(),
+            headers,
+            body: self.body,
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::MessageBuilder;
+    use crate::mail::{encoding::encode_display_name, Header, Mailbox, Mailboxes};
+
+    use super::{fold_header, MessageBuilder};
 
     #[test]
-    fn it_creates_a_builder_and_builds_an_empty_message() {
+    fn it_creates_a_builder_and_builds_a_message_with_default_headers() {
         let message = MessageBuilder::new().build();
-        assert_eq!(message.headers.len(), 0);
+
+        assert!(message.headers.iter().any(|h| matches!(h, Header::Date(_))));
+        assert!(message
+            .headers
+            .iter()
+            .any(|h| matches!(h, Header::MessageId(_))));
+    }
+
+    #[test]
+    fn it_does_not_override_an_explicit_date_or_message_id() {
+        let message = MessageBuilder::new()
+            .header(Header::Date("Mon, 1 Jan 2024 00:00:00 +0000".to_owned()))
+            .header(Header::MessageId("<fixed@domain.com>".to_owned()))
+            .build();
+
+        let dates: Vec<_> = message
+            .headers
+            .iter()
+            .filter(|h| matches!(h, Header::Date(_)))
+            .collect();
+        let message_ids: Vec<_> = message
+            .headers
+            .iter()
+            .filter(|h| matches!(h, Header::MessageId(_)))
+            .collect();
+
+        assert_eq!(dates.len(), 1);
+        assert_eq!(message_ids.len(), 1);
+    }
+
+    #[test]
+    fn it_renders_headers_body_and_blank_line_separator() {
+        let message = MessageBuilder::new()
+            .header(Header::Date("Mon, 1 Jan 2024 00:00:00 +0000".to_owned()))
+            .header(Header::MessageId("<fixed@domain.com>".to_owned()))
+            .from("sender <sender@domain.com>".parse().unwrap())
+            .body("Hello, world!")
+            .build();
+
+        assert_eq!(
+            message.to_string(),
+            "Date: Mon, 1 Jan 2024 00:00:00 +0000\r\n\
+             Message-ID: <fixed@domain.com>\r\n\
+             From: sender <sender@domain.com>\r\n\
+             \r\n\
+             Hello, world!"
+        );
+    }
+
+    #[test]
+    fn it_folds_long_header_lines_at_whitespace() {
+        let value =
+            "one two three four five six seven eight nine ten eleven twelve thirteen fourteen";
+        let folded = fold_header("Subject", value);
+
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= 78);
+        }
+        assert!(folded.contains("\r\n "));
+    }
+
+    #[test]
+    fn it_does_not_reintroduce_a_blank_line_when_folding_an_already_folded_encoded_word() {
+        let name = "日".repeat(40);
+        let folded = fold_header(
+            "To",
+            &format!("{} <user@domain.com>", encode_display_name(&name)),
+        );
+
+        assert!(!folded.contains("\r\n\r\n"));
+    }
+
+    #[test]
+    fn it_renders_a_message_with_a_long_non_ascii_display_name_without_corrupting_the_headers() {
+        let name = "日".repeat(40);
+        let to: Mailboxes =
+            Mailbox::try_new(Some(name.as_str()), "user@domain.com".parse().unwrap())
+                .unwrap()
+                .into();
+
+        let message = MessageBuilder::new()
+            .header(Header::Date("Mon, 1 Jan 2024 00:00:00 +0000".to_owned()))
+            .header(Header::MessageId("<fixed@domain.com>".to_owned()))
+            .to(to)
+            .body("Hello, world!")
+            .build();
+
+        let rendered = message.to_string();
+
+        // The only blank line in a well-formed message is the header/body separator.
+        assert_eq!(rendered.matches("\r\n\r\n").count(), 1);
+        assert!(rendered.ends_with("Hello, world!"));
+    }
+
+    #[test]
+    fn it_omits_bcc_headers_from_the_rendered_message() {
+        let bcc: Mailboxes = "carol <carol@x.com>".parse().unwrap();
+
+        let message = MessageBuilder::new()
+            .header(Header::Date("Mon, 1 Jan 2024 00:00:00 +0000".to_owned()))
+            .header(Header::MessageId("<fixed@domain.com>".to_owned()))
+            .header(Header::Bcc(bcc))
+            .body("Hello, world!")
+            .build();
+
+        assert!(!message.to_string().contains("Bcc"));
+        assert!(!message.to_string().contains("carol@x.com"));
     }
 }
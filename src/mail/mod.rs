@@ -1,10 +1,16 @@
 mod address;
+mod datetime;
+mod domain;
+mod encoding;
+mod envelope;
 mod header;
 mod mailbox;
 mod message;
+mod punycode;
 mod validation;
 
 pub use address::Address;
+pub use envelope::Envelope;
 pub use header::Header;
-pub use mailbox::{Mailbox, Mailboxes};
-pub use message::MessageBuilder;
+pub use mailbox::{AddressOrGroup, Mailbox, Mailboxes};
+pub use message::{Message, MessageBuilder};
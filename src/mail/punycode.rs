@@ -0,0 +1,115 @@
+//! RFC 3492 Punycode encoding, used to convert internationalized domain labels into their ASCII
+//! `xn--` form.
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+
+    let mut k = 0;
+    while delta > (BASE - TMIN) * TMAX / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+
+    k + (BASE - TMIN + 1) * delta / (delta + SKEW)
+}
+
+fn digit_to_basic(digit: u32) -> char {
+    if digit < 26 {
+        (b'a' + digit as u8) as char
+    } else {
+        (b'0' + (digit - 26) as u8) as char
+    }
+}
+
+/// Encodes `label` (which must contain at least one non-ASCII character) as a Punycode string,
+/// without the `xn--` prefix.
+pub fn encode(label: &str) -> String {
+    let code_points: Vec<u32> = label.chars().map(|c| c as u32).collect();
+    let basic: Vec<u32> = code_points.iter().copied().filter(|c| *c < 128).collect();
+
+    let mut output: String = basic.iter().map(|&c| c as u8 as char).collect();
+    let b = basic.len();
+    let mut h = b;
+
+    if b > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while h < code_points.len() {
+        let m = *code_points.iter().filter(|&&c| c >= n).min().unwrap();
+        delta += (m - n) * (h as u32 + 1);
+        n = m;
+
+        for &c in &code_points {
+            if c < n {
+                delta += 1;
+            }
+
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+
+                loop {
+                    let t = if k <= bias {
+                        TMIN
+                    } else if k >= bias + TMAX {
+                        TMAX
+                    } else {
+                        k - bias
+                    };
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit_to_basic(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit_to_basic(q));
+                bias = adapt(delta, (h + 1) as u32, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+
+        delta += 1;
+        n += 1;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::encode;
+
+    #[test]
+    fn it_encodes_a_german_label() {
+        assert_eq!(encode("bücher"), "bcher-kva");
+    }
+
+    #[test]
+    fn it_encodes_a_label_with_no_basic_code_points() {
+        assert_eq!(encode("ü"), "tda");
+    }
+
+    #[test]
+    fn it_appends_the_delimiter_to_a_pure_ascii_label() {
+        assert_eq!(encode("books"), "books-");
+    }
+}
@@ -0,0 +1,44 @@
+/// Characters that must not appear unescaped in an address user, domain, or mailbox name.
+pub(crate) const SPECIALS: &[char] = &[
+    '(', ')', '<', '>', '[', ']', ':', ';', '@', '\\', ',', '"',
+];
+
+#[derive(Debug)]
+pub enum InvalidPartError {
+    Empty,
+    InvalidCharacter(char),
+}
+
+/// Validates that `part` is non-empty and free of whitespace and of the RFC 5322 `specials`
+/// other than `.`.
+pub fn validate_part(part: &str) -> Result<(), InvalidPartError> {
+    if part.is_empty() {
+        return Err(InvalidPartError::Empty);
+    }
+
+    for c in part.chars() {
+        if c.is_whitespace() || c.is_control() || SPECIALS.contains(&c) {
+            return Err(InvalidPartError::InvalidCharacter(c));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a mailbox display name. Unlike [`validate_part`], whitespace and `specials` are
+/// allowed here since `Mailbox`'s `Display` impl quotes or RFC 2047-encodes the name as needed;
+/// only an empty name or a control character (which can't be represented even quoted) is
+/// rejected.
+pub fn validate_name(name: &str) -> Result<(), InvalidPartError> {
+    if name.is_empty() {
+        return Err(InvalidPartError::Empty);
+    }
+
+    for c in name.chars() {
+        if c.is_control() {
+            return Err(InvalidPartError::InvalidCharacter(c));
+        }
+    }
+
+    Ok(())
+}